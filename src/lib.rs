@@ -18,18 +18,51 @@
 
 pub use tf2_enum;
 
+use std::collections::HashSet;
 use std::num::{IntErrorKind, ParseIntError};
 use std::fmt;
 use std::convert::TryFrom;
+use std::ops::Range;
 use tf2_enum::num_enum::{TryFromPrimitive, TryFromPrimitiveError};
 use tf2_enum::{Quality, KillstreakTier, Wear, Paint, Sheen, Killstreaker};
-use serde::{Serialize, Serializer, de::{self, Visitor}};
+use serde::{Serialize, Serializer, de::{self, Visitor}, ser::SerializeMap};
 
 /// Trait for converting to a SKU string.
 pub trait SKUString {
     fn to_sku_string(&self) -> String;
 }
 
+/// Options for configuring how a SKU string is parsed by [`SKU::try_from_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkuParseOptions {
+    /// If `true`, an attribute that is not recognized produces [`ParseError::UnknownAttribute`]
+    /// instead of being silently ignored. Defaults to `false`.
+    pub reject_unknown_attributes: bool,
+    /// If `true`, an attribute that is set more than once produces
+    /// [`ParseError::DuplicateAttribute`] instead of silently overwriting the earlier value.
+    /// Defaults to `false`.
+    pub reject_duplicate_attributes: bool,
+    /// If `true`, a missing or unrecognized quality falls back to `default_quality` instead of
+    /// producing an error. Defaults to `false`.
+    pub allow_missing_quality: bool,
+    /// The quality used in place of a missing or unrecognized quality when
+    /// `allow_missing_quality` is `true`. Defaults to [`Quality::Normal`].
+    pub default_quality: Quality,
+}
+
+/// All flags default to `false` and `default_quality` defaults to [`Quality::Normal`], which
+/// matches the behavior of [`TryFrom<&str>`].
+impl Default for SkuParseOptions {
+    fn default() -> Self {
+        Self {
+            reject_unknown_attributes: false,
+            reject_duplicate_attributes: false,
+            allow_missing_quality: false,
+            default_quality: Quality::Normal,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SKU {
     /// This can be negative at times to refer to items that are not defined in the schema e.g. 
@@ -132,30 +165,32 @@ impl SKU {
     /// ```
     pub fn from_str(string: &str) -> Self {
         let mut parsed = Self::default();
-        let mut sku_split = string.split(';');
-        let defindex_str = sku_split.next()
-            .unwrap_or_default();
-        let quality_str = sku_split.next()
-            .unwrap_or_default();
-        
+        let options = SkuParseOptions::default();
+        let mut seen = HashSet::new();
+        let mut segments = sku_segments(string);
+        let (_, defindex_start, defindex_str) = segments.next()
+            .unwrap_or((0, 0, ""));
+        let (_, quality_start, quality_str) = segments.next()
+            .unwrap_or((1, defindex_start, ""));
+
         if let Ok(defindex) = defindex_str.parse::<i32>() {
             parsed.defindex = defindex;
         } else {
             parsed.defindex = -1;
-            parse_sku_element(&mut parsed, defindex_str).ok();
+            parse_sku_element(&mut parsed, defindex_str, &options, &mut seen, 0, defindex_start).ok();
         }
-        
-        if let Ok(quality) = parse_enum_u32::<Quality>("quality", quality_str) {
+
+        if let Ok(quality) = parse_enum_u32::<Quality>("quality", quality_str, quality_str, 1, quality_start) {
             parsed.quality = quality;
         } else {
             parsed.quality = Quality::Rarity2;
-            parse_sku_element(&mut parsed, quality_str).ok();
+            parse_sku_element(&mut parsed, quality_str, &options, &mut seen, 1, quality_start).ok();
         }
-        
-        while let Some(element) = sku_split.next() {
-            parse_sku_element(&mut parsed, element).ok();
+
+        for (segment, start, element) in segments {
+            parse_sku_element(&mut parsed, element, &options, &mut seen, segment, start).ok();
         }
-        
+
         parsed
     }
 }
@@ -174,100 +209,111 @@ impl SKUString for &SKU {
     }
 }
 
-/// Formats SKU attributes into a string.
-/// 
-/// # Examples
-///
-/// ```
-/// use tf2_sku::{SKU, tf2_enum::{Quality, KillstreakTier}};
-/// 
-/// let mut sku = SKU::new(264, Quality::Strange);
-/// 
-/// sku.killstreak_tier = Some(KillstreakTier::Professional);
-/// 
-/// assert_eq!(sku.to_string(), "264;11;kt-3");
-/// ```
-impl fmt::Display for SKU {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut string = format!("{};{}", self.defindex, u32::from(self.quality));
-        
+impl SKU {
+    /// Writes the SKU string representation into `w`. This is what backs [`Display`](fmt::Display)
+    /// and [`SKUString::to_sku_string`], but writing directly into a shared buffer avoids the
+    /// per-SKU `String` that `to_string` and `to_sku_string` allocate, which matters when
+    /// serializing many SKUs in a row e.g. for a large item inventory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tf2_sku::{SKU, tf2_enum::Quality};
+    /// use std::fmt::Write;
+    ///
+    /// let sku = SKU::new(264, Quality::Strange);
+    /// let mut buf = String::new();
+    ///
+    /// sku.write_to(&mut buf).unwrap();
+    /// assert_eq!(buf, "264;11");
+    /// ```
+    pub fn write_to<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        write!(w, "{};{}", self.defindex, u32::from(self.quality))?;
+
         if let Some(particle) = self.particle {
-            string.push_str(";u");
-            string.push_str(particle.to_string().as_str());
+            write!(w, ";u{particle}")?;
         }
-        
+
         if !self.craftable {
-            string.push_str(";uncraftable");
+            w.write_str(";uncraftable")?;
         }
-        
+
         if self.australium {
-            string.push_str(";australium");
+            w.write_str(";australium")?;
         }
-        
+
         if self.strange {
-            string.push_str(";strange");
+            w.write_str(";strange")?;
         }
-        
+
         if let Some(wear) = self.wear {
-            string.push_str(";w");
-            string.push_str(u32::from(wear).to_string().as_str());
+            write!(w, ";w{}", u32::from(wear))?;
         }
-        
+
         if let Some(skin) = self.skin {
-            string.push_str(";pk");
-            string.push_str(skin.to_string().as_str());
+            write!(w, ";pk{skin}")?;
         }
-        
+
         if let Some(killstreak_tier) = self.killstreak_tier {
-            string.push_str(";kt-");
-            string.push_str(u32::from(killstreak_tier).to_string().as_str());
+            write!(w, ";kt-{}", u32::from(killstreak_tier))?;
         }
-        
+
         if self.festivized {
-            string.push_str(";festive");
+            w.write_str(";festive")?;
         }
 
         if let Some(crate_number) = self.crate_number {
-            string.push_str(";c");
-            string.push_str(crate_number.to_string().as_str());
+            write!(w, ";c{crate_number}")?;
         }
 
         if let Some(craft_number) = self.craft_number {
-            string.push_str(";n");
-            string.push_str(craft_number.to_string().as_str());
+            write!(w, ";n{craft_number}")?;
         }
-        
+
         if let Some(target_defindex) = self.target_defindex {
-            string.push_str(";td-");
-            string.push_str(target_defindex.to_string().as_str());
+            write!(w, ";td-{target_defindex}")?;
         }
-        
+
         if let Some(output_defindex) = self.output_defindex {
-            string.push_str(";od-");
-            string.push_str(output_defindex.to_string().as_str());
+            write!(w, ";od-{output_defindex}")?;
         }
-        
+
         if let Some(output_quality) = self.output_quality {
-            string.push_str(";oq-");
-            string.push_str(u32::from(output_quality).to_string().as_str());
+            write!(w, ";oq-{}", u32::from(output_quality))?;
         }
-        
+
         if let Some(paint) = self.paint {
-            string.push_str(";p");
-            string.push_str(u32::from(paint).to_string().as_str());
+            write!(w, ";p{}", u32::from(paint))?;
         }
-        
+
         if let Some(sheen) = self.sheen {
-            string.push_str(";ks-");
-            string.push_str(u32::from(sheen).to_string().as_str());
+            write!(w, ";ks-{}", u32::from(sheen))?;
         }
-        
+
         if let Some(killstreaker) = self.killstreaker {
-            string.push_str(";ke-");
-            string.push_str(u32::from(killstreaker).to_string().as_str());
+            write!(w, ";ke-{}", u32::from(killstreaker))?;
         }
-        
-        write!(f, "{}", string)
+
+        Ok(())
+    }
+}
+
+/// Formats SKU attributes into a string.
+///
+/// # Examples
+///
+/// ```
+/// use tf2_sku::{SKU, tf2_enum::{Quality, KillstreakTier}};
+///
+/// let mut sku = SKU::new(264, Quality::Strange);
+///
+/// sku.killstreak_tier = Some(KillstreakTier::Professional);
+///
+/// assert_eq!(sku.to_string(), "264;11;kt-3");
+/// ```
+impl fmt::Display for SKU {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
     }
 }
 
@@ -288,33 +334,95 @@ impl fmt::Display for SKU {
 /// ```
 impl TryFrom<&str> for SKU {
     type Error = ParseError;
-        
+
     fn try_from(string: &str) -> Result<Self, Self::Error> {
-        let mut sku_split = string.split(';');
-        let defindex_str = sku_split.next()
-            .ok_or(ParseError::InvalidFormat)?;
-        let quality_str = sku_split.next()
-            .ok_or(ParseError::InvalidFormat)?;
+        Self::try_from_with_options(string, &SkuParseOptions::default())
+    }
+}
+
+impl SKU {
+    /// Attempts to parse a SKU from a string using the given [`SkuParseOptions`]. With
+    /// [`SkuParseOptions::default`] this behaves identically to [`TryFrom<&str>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tf2_sku::{SKU, SkuParseOptions, ParseError};
+    ///
+    /// let options = SkuParseOptions {
+    ///     reject_unknown_attributes: true,
+    ///     ..SkuParseOptions::default()
+    /// };
+    ///
+    /// assert!(matches!(
+    ///     SKU::try_from_with_options("1;5;superspecial", &options),
+    ///     Err(ParseError::UnknownAttribute { .. }),
+    /// ));
+    /// ```
+    pub fn try_from_with_options(
+        string: &str,
+        options: &SkuParseOptions,
+    ) -> Result<Self, ParseError> {
+        let mut segments = sku_segments(string);
+        // `str::split` always yields at least one segment, even for an empty string.
+        let (_, defindex_start, defindex_str) = segments.next()
+            .unwrap();
+        let quality_segment = segments.next();
         let defindex = defindex_str.parse::<i32>()
             .map_err(|error| ParseError::ParseInt {
                 key: "defindex",
                 error,
+                segment: 0,
+                element: defindex_str.to_string(),
+                start: defindex_start,
             })?;
-        let quality = parse_enum_u32::<Quality>("quality", quality_str)?;
+        let quality = if let Some((segment, start, quality_str)) = quality_segment {
+            match parse_enum_u32::<Quality>("quality", quality_str, quality_str, segment, start) {
+                Ok(quality) => quality,
+                Err(_) if options.allow_missing_quality => options.default_quality,
+                Err(error) => return Err(error),
+            }
+        } else if options.allow_missing_quality {
+            options.default_quality
+        } else {
+            return Err(ParseError::InvalidFormat {
+                segment: 1,
+                element: String::new(),
+                start: string.len(),
+            });
+        };
         let mut parsed = SKU::new(defindex, quality);
-        
-        while let Some(element) = sku_split.next() {
-            parse_sku_element(&mut parsed, element)?;
+        let mut seen = HashSet::new();
+
+        for (segment, start, element) in segments {
+            parse_sku_element(&mut parsed, element, options, &mut seen, segment, start)?;
         }
-        
+
         Ok(parsed)
     }
 }
 
+/// Splits a SKU string on `;`, yielding each segment's zero-based index, byte offset, and text.
+fn sku_segments(string: &str) -> impl Iterator<Item = (usize, usize, &str)> {
+    let mut start = 0;
+
+    string.split(';').enumerate().map(move |(segment, element)| {
+        let element_start = start;
+
+        start += element.len() + 1;
+
+        (segment, element_start, element)
+    })
+}
+
 /// Parses a single SKU attribute.
-fn parse_sku_element<'a>(
+fn parse_sku_element(
     parsed: &mut SKU,
     element: &str,
+    options: &SkuParseOptions,
+    seen: &mut HashSet<&'static str>,
+    segment: usize,
+    start: usize,
 ) -> Result<(), ParseError> {
     let mut split_at = element.len();
     
@@ -332,20 +440,62 @@ fn parse_sku_element<'a>(
     // character is multi-byte it is not a valid digit, so it will stop immediately and `split_at`
     // will be the total byte length of the string.
     let (name, value) = element.split_at(split_at);
-    
+
+    // Look up the canonical key for this attribute before parsing it, so unknown and duplicate
+    // attributes can be detected regardless of whether parsing the value itself succeeds.
+    let key: Option<&'static str> = match name {
+        "u" => Some("particle"),
+        "w" => Some("wear"),
+        "n" => Some("craft number"),
+        "c" => Some("crate number"),
+        "p" => Some("paint"),
+        "pk" => Some("skin"),
+        "kt-" => Some("killstreak tier"),
+        "td-" => Some("target defindex"),
+        "od-" => Some("output defindex"),
+        "oq-" => Some("output quality"),
+        "ks-" => Some("sheen"),
+        "ke-" => Some("killstreaker"),
+        "uncraftable" => Some("craftable"),
+        "australium" => Some("australium"),
+        "strange" => Some("strange"),
+        "festive" => Some("festivized"),
+        _ => None,
+    };
+
+    match key {
+        Some(key) if options.reject_duplicate_attributes && !seen.insert(key) => {
+            return Err(ParseError::DuplicateAttribute {
+                key,
+                segment,
+                element: element.to_string(),
+                start,
+            });
+        },
+        None if options.reject_unknown_attributes => {
+            return Err(ParseError::UnknownAttribute {
+                name: name.to_string(),
+                segment,
+                element: element.to_string(),
+                start,
+            });
+        },
+        _ => {},
+    }
+
     match name {
-        "u" => parsed.particle = Some(parse_u32("particle", value)?),
-        "w" => parsed.wear = Some(parse_enum_u32("wear", value)?),
-        "n" => parsed.craft_number = Some(parse_u32("craft number", value)?),
-        "c" => parsed.crate_number = Some(parse_u32("crate number", value)?),
-        "p" => parsed.paint = Some(parse_enum_u32("paint", value)?),
-        "pk" => parsed.skin = Some(parse_u32("skin", value)?),
-        "kt-" => parsed.killstreak_tier = Some(parse_enum_u32("killstreak tier", value)?),
-        "td-" => parsed.target_defindex = Some(parse_u32("target defindex", value)?),
-        "od-" => parsed.output_defindex = Some(parse_u32("output defindex", value)?),
-        "oq-" => parsed.output_quality = Some(parse_enum_u32("output quality", value)?),
-        "ks-" => parsed.sheen = Some(parse_enum_u32("sheen", value)?),
-        "ke-" => parsed.killstreaker = Some(parse_enum_u32("killstreaker", value)?),
+        "u" => parsed.particle = Some(parse_u32("particle", value, element, segment, start)?),
+        "w" => parsed.wear = Some(parse_enum_u32("wear", value, element, segment, start)?),
+        "n" => parsed.craft_number = Some(parse_u32("craft number", value, element, segment, start)?),
+        "c" => parsed.crate_number = Some(parse_u32("crate number", value, element, segment, start)?),
+        "p" => parsed.paint = Some(parse_enum_u32("paint", value, element, segment, start)?),
+        "pk" => parsed.skin = Some(parse_u32("skin", value, element, segment, start)?),
+        "kt-" => parsed.killstreak_tier = Some(parse_enum_u32("killstreak tier", value, element, segment, start)?),
+        "td-" => parsed.target_defindex = Some(parse_u32("target defindex", value, element, segment, start)?),
+        "od-" => parsed.output_defindex = Some(parse_u32("output defindex", value, element, segment, start)?),
+        "oq-" => parsed.output_quality = Some(parse_enum_u32("output quality", value, element, segment, start)?),
+        "ks-" => parsed.sheen = Some(parse_enum_u32("sheen", value, element, segment, start)?),
+        "ke-" => parsed.killstreaker = Some(parse_enum_u32("killstreaker", value, element, segment, start)?),
         "uncraftable" => parsed.craftable = false,
         "australium" => parsed.australium = true,
         "strange" => parsed.strange = true,
@@ -353,7 +503,7 @@ fn parse_sku_element<'a>(
         // ignore
         _ => {},
     }
-    
+
     Ok(())
 }
 
@@ -364,14 +514,78 @@ pub enum ParseError {
     ParseInt {
         key: &'static str,
         error: ParseIntError,
+        segment: usize,
+        element: String,
+        start: usize,
     },
     /// The SKU format is not valid. Must begin with a defindex and a quality e.g. "5021;6".
-    InvalidFormat,
+    InvalidFormat {
+        segment: usize,
+        element: String,
+        start: usize,
+    },
     /// An attribute value is not valid.
     InvalidValue {
         key: &'static str,
         number: u32,
+        segment: usize,
+        element: String,
+        start: usize,
+    },
+    /// An attribute was not recognized. Only produced when
+    /// [`SkuParseOptions::reject_unknown_attributes`] is enabled.
+    UnknownAttribute {
+        name: String,
+        segment: usize,
+        element: String,
+        start: usize,
     },
+    /// An attribute was specified more than once. Only produced when
+    /// [`SkuParseOptions::reject_duplicate_attributes`] is enabled.
+    DuplicateAttribute {
+        key: &'static str,
+        segment: usize,
+        element: String,
+        start: usize,
+    },
+}
+
+impl ParseError {
+    /// The byte range of the offending segment within the original SKU string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tf2_sku::SKU;
+    /// use std::convert::TryFrom;
+    ///
+    /// // "122" is not a valid quality
+    /// let error = SKU::try_from("1071;122").unwrap_err();
+    ///
+    /// assert_eq!(error.span(), 5..8);
+    /// ```
+    pub fn span(&self) -> Range<usize> {
+        let (start, element) = match self {
+            ParseError::ParseInt { start, element, .. }
+            | ParseError::InvalidFormat { start, element, .. }
+            | ParseError::InvalidValue { start, element, .. }
+            | ParseError::UnknownAttribute { start, element, .. }
+            | ParseError::DuplicateAttribute { start, element, .. } => (*start, element),
+        };
+
+        start..start + element.len()
+    }
+
+    /// The zero-based index of the offending segment in the `;`-split input.
+    pub fn segment(&self) -> usize {
+        match self {
+            ParseError::ParseInt { segment, .. }
+            | ParseError::InvalidFormat { segment, .. }
+            | ParseError::InvalidValue { segment, .. }
+            | ParseError::UnknownAttribute { segment, .. }
+            | ParseError::DuplicateAttribute { segment, .. } => *segment,
+        }
+    }
 }
 
 impl std::error::Error for ParseError {}
@@ -381,21 +595,46 @@ impl fmt::Display for ParseError {
         match self {
             ParseError::ParseInt {
                 key,
-                error ,
-            } => match *error.kind() {
-                IntErrorKind::Empty => write!(f, "Value for {key} in SKU is empty."),
-                IntErrorKind::InvalidDigit => write!(f, "Value for {key} in SKU contains invalid digit."),
-                IntErrorKind::PosOverflow => write!(f, "Value for {key} in SKU overflows integer bounds."),
-                IntErrorKind::NegOverflow => write!(f, "Value for {key} in SKU underflows integer bounds."),
-                // shouldn't occur
-                IntErrorKind::Zero => write!(f, "Value for {key} in SKU zero for non-zero type."),
-                _ => write!(f, "Value for {key} in SKU could not be parsed: {error}"),
+                error,
+                segment,
+                element,
+                ..
+            } => {
+                match *error.kind() {
+                    IntErrorKind::Empty => write!(f, "Value for {key} in SKU is empty"),
+                    IntErrorKind::InvalidDigit => write!(f, "Value for {key} in SKU contains invalid digit"),
+                    IntErrorKind::PosOverflow => write!(f, "Value for {key} in SKU overflows integer bounds"),
+                    IntErrorKind::NegOverflow => write!(f, "Value for {key} in SKU underflows integer bounds"),
+                    // shouldn't occur
+                    IntErrorKind::Zero => write!(f, "Value for {key} in SKU zero for non-zero type"),
+                    _ => write!(f, "Value for {key} in SKU could not be parsed: {error}"),
+                }?;
+                write!(f, " at segment {segment} \"{element}\"")
             },
-            ParseError::InvalidFormat => write!(f, "Invalid SKU format. Must begin with a defindex followed by a quality e.g. \"5021;6\""),
+            ParseError::InvalidFormat {
+                segment,
+                element,
+                ..
+            } => write!(f, "Invalid SKU format. Must begin with a defindex followed by a quality e.g. \"5021;6\" at segment {segment} \"{element}\""),
             ParseError::InvalidValue {
                 key,
                 number,
-            } => write!(f, "Unknown {key}: {number}"),
+                segment,
+                element,
+                ..
+            } => write!(f, "Unknown {key}: {number} at segment {segment} \"{element}\""),
+            ParseError::UnknownAttribute {
+                name,
+                segment,
+                element,
+                ..
+            } => write!(f, "Unknown attribute: {name} at segment {segment} \"{element}\""),
+            ParseError::DuplicateAttribute {
+                key,
+                segment,
+                element,
+                ..
+            } => write!(f, "Attribute {key} in SKU was specified more than once at segment {segment} \"{element}\""),
         }
     }
 }
@@ -409,6 +648,15 @@ impl Serialize for SKU {
     }
 }
 
+/// Accepts either a SKU string (e.g. `"264;11"`) or a map of named attributes (see
+/// [`SkuStruct`]).
+///
+/// Because the map form requires the deserializer to inspect the input to tell which form it's
+/// looking at, this calls [`Deserializer::deserialize_any`](de::Deserializer::deserialize_any)
+/// rather than `deserialize_str`. **This is a breaking change for non-self-describing formats**
+/// like `bincode` or `postcard`, which don't support `deserialize_any` and will error at runtime
+/// instead of decoding the string form they previously accepted. Crates relying on those formats
+/// should use [`SKU::to_bytes`]/[`SKU::from_bytes`] instead.
 impl<'de> de::Deserialize<'de> for SKU {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -420,40 +668,618 @@ impl<'de> de::Deserialize<'de> for SKU {
             type Value = SKU;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                write!(formatter, "a string")
+                write!(formatter, "a SKU string or a map of SKU attributes")
             }
-            
+
             fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
                 Self::Value::try_from(s).map_err(de::Error::custom)
             }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let mut defindex = None;
+                let mut quality = None;
+                let mut sku = SKU::default();
+
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "defindex" => defindex = Some(map.next_value::<i32>()?),
+                        "quality" => quality = Some(deserialize_named_value(&mut map)?),
+                        "craftable" => sku.craftable = map.next_value::<bool>()?,
+                        "australium" => sku.australium = map.next_value::<bool>()?,
+                        "strange" => sku.strange = map.next_value::<bool>()?,
+                        "festivized" => sku.festivized = map.next_value::<bool>()?,
+                        "particle" => sku.particle = Some(map.next_value::<u32>()?),
+                        "skin" => sku.skin = Some(map.next_value::<u32>()?),
+                        "killstreak_tier" => sku.killstreak_tier = Some(deserialize_named_value(&mut map)?),
+                        "wear" => sku.wear = Some(deserialize_named_value(&mut map)?),
+                        "target_defindex" => sku.target_defindex = Some(map.next_value::<u32>()?),
+                        "output_defindex" => sku.output_defindex = Some(map.next_value::<u32>()?),
+                        "output_quality" => sku.output_quality = Some(deserialize_named_value(&mut map)?),
+                        "craft_number" => sku.craft_number = Some(map.next_value::<u32>()?),
+                        "crate_number" => sku.crate_number = Some(map.next_value::<u32>()?),
+                        "paint" => sku.paint = Some(deserialize_named_value(&mut map)?),
+                        "sheen" => sku.sheen = Some(deserialize_named_value(&mut map)?),
+                        "killstreaker" => sku.killstreaker = Some(deserialize_named_value(&mut map)?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        },
+                    }
+                }
+
+                sku.defindex = defindex.ok_or_else(|| de::Error::missing_field("defindex"))?;
+                sku.quality = quality.ok_or_else(|| de::Error::missing_field("quality"))?;
+
+                Ok(sku)
+            }
         }
 
-        deserializer.deserialize_str(SKUVisitor)
+        deserializer.deserialize_any(SKUVisitor)
     }
 }
 
-fn parse_enum_u32<T>(key: &'static str, s: &str) -> Result<T, ParseError>
+/// Reads the next map value as a string and parses it into an enum by name, e.g. `"Strange"`.
+fn deserialize_named_value<'de, T, A>(map: &mut A) -> Result<T, A::Error>
+where
+    T: std::str::FromStr,
+    T::Err: fmt::Display,
+    A: de::MapAccess<'de>,
+{
+    map.next_value::<String>()?
+        .parse::<T>()
+        .map_err(de::Error::custom)
+}
+
+/// Wraps a [`SKU`] to serialize it as a structured object with named fields, e.g.
+/// `{"defindex":264,"quality":"Strange"}`, instead of the terse `"264;11"` string form.
+/// Fields left at their default value (`None`, `false` for flags that default to `false`,
+/// `true` for `craftable` which defaults to `true`) are omitted to keep the output concise.
+///
+/// This is intended for human-readable formats like RON, TOML, or JSON config files, where
+/// a self-describing object is more useful than the compact SKU string. [`SKU`]'s own
+/// [`Deserialize`](de::Deserialize) impl accepts both forms, so round-tripping through
+/// `SkuStruct` and back into a plain string-serialized [`SKU`] works transparently.
+///
+/// # Examples
+///
+/// ```
+/// use tf2_sku::{SKU, SkuStruct, tf2_enum::Quality};
+///
+/// let sku = SKU::new(264, Quality::Strange);
+/// let json = serde_json::to_string(&SkuStruct(sku)).unwrap();
+///
+/// assert_eq!(json, r#"{"defindex":264,"quality":"Strange"}"#);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SkuStruct(pub SKU);
+
+impl Serialize for SkuStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let sku = &self.0;
+        let mut map = serializer.serialize_map(None)?;
+
+        map.serialize_entry("defindex", &sku.defindex)?;
+        map.serialize_entry("quality", &sku.quality.to_string())?;
+
+        if !sku.craftable {
+            map.serialize_entry("craftable", &false)?;
+        }
+
+        if sku.australium {
+            map.serialize_entry("australium", &true)?;
+        }
+
+        if sku.strange {
+            map.serialize_entry("strange", &true)?;
+        }
+
+        if sku.festivized {
+            map.serialize_entry("festivized", &true)?;
+        }
+
+        if let Some(particle) = sku.particle {
+            map.serialize_entry("particle", &particle)?;
+        }
+
+        if let Some(skin) = sku.skin {
+            map.serialize_entry("skin", &skin)?;
+        }
+
+        if let Some(killstreak_tier) = sku.killstreak_tier {
+            map.serialize_entry("killstreak_tier", &killstreak_tier.to_string())?;
+        }
+
+        if let Some(wear) = sku.wear {
+            map.serialize_entry("wear", &wear.to_string())?;
+        }
+
+        if let Some(target_defindex) = sku.target_defindex {
+            map.serialize_entry("target_defindex", &target_defindex)?;
+        }
+
+        if let Some(output_defindex) = sku.output_defindex {
+            map.serialize_entry("output_defindex", &output_defindex)?;
+        }
+
+        if let Some(output_quality) = sku.output_quality {
+            map.serialize_entry("output_quality", &output_quality.to_string())?;
+        }
+
+        if let Some(craft_number) = sku.craft_number {
+            map.serialize_entry("craft_number", &craft_number)?;
+        }
+
+        if let Some(crate_number) = sku.crate_number {
+            map.serialize_entry("crate_number", &crate_number)?;
+        }
+
+        if let Some(paint) = sku.paint {
+            map.serialize_entry("paint", &paint.to_string())?;
+        }
+
+        if let Some(sheen) = sku.sheen {
+            map.serialize_entry("sheen", &sheen.to_string())?;
+        }
+
+        if let Some(killstreaker) = sku.killstreaker {
+            map.serialize_entry("killstreaker", &killstreaker.to_string())?;
+        }
+
+        map.end()
+    }
+}
+
+fn parse_enum_u32<T>(
+    key: &'static str,
+    value: &str,
+    element: &str,
+    segment: usize,
+    start: usize,
+) -> Result<T, ParseError>
 where T:
     TryFromPrimitive<Primitive = u32>,
 {
-    T::try_from_primitive(parse_u32(key, s)?)
+    T::try_from_primitive(parse_u32(key, value, element, segment, start)?)
         .map_err(|TryFromPrimitiveError { number }| ParseError::InvalidValue {
             key,
             number,
+            segment,
+            element: element.to_string(),
+            start,
         })
 }
 
-fn parse_u32(key: &'static str, value: &str) -> Result<u32, ParseError> {
+fn parse_u32(
+    key: &'static str,
+    value: &str,
+    element: &str,
+    segment: usize,
+    start: usize,
+) -> Result<u32, ParseError> {
     value.parse::<u32>()
         .map_err(|error| ParseError::ParseInt {
             key,
             error,
+            segment,
+            element: element.to_string(),
+            start,
+        })
+}
+
+// Bit positions of each optional/boolean field within the 2-byte presence bitmask used by
+// `SKU::to_bytes`/`SKU::from_bytes`.
+const FLAG_CRAFTABLE: u16 = 1 << 0;
+const FLAG_AUSTRALIUM: u16 = 1 << 1;
+const FLAG_STRANGE: u16 = 1 << 2;
+const FLAG_FESTIVIZED: u16 = 1 << 3;
+const FLAG_PARTICLE: u16 = 1 << 4;
+const FLAG_SKIN: u16 = 1 << 5;
+const FLAG_KILLSTREAK_TIER: u16 = 1 << 6;
+const FLAG_WEAR: u16 = 1 << 7;
+const FLAG_TARGET_DEFINDEX: u16 = 1 << 8;
+const FLAG_OUTPUT_DEFINDEX: u16 = 1 << 9;
+const FLAG_OUTPUT_QUALITY: u16 = 1 << 10;
+const FLAG_CRAFT_NUMBER: u16 = 1 << 11;
+const FLAG_CRATE_NUMBER: u16 = 1 << 12;
+const FLAG_PAINT: u16 = 1 << 13;
+const FLAG_SHEEN: u16 = 1 << 14;
+const FLAG_KILLSTREAKER: u16 = 1 << 15;
+
+impl SKU {
+    /// Encodes this SKU into a compact, fixed-schema binary form for storage or network
+    /// transport, where the `;`-delimited string form is wasteful. A 2-byte bitmask marks which
+    /// optional fields and flags are present, followed by the present fields in a fixed order as
+    /// LEB128 varints (`defindex` is zig-zag encoded since it can be negative). Use
+    /// [`SKU::from_bytes`] to decode, or [`SKU::to_base64`]/[`SKU::from_base64`] for a form that
+    /// survives JSON/URLs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tf2_sku::{SKU, tf2_enum::Quality};
+    ///
+    /// let sku = SKU::new(264, Quality::Strange);
+    ///
+    /// assert_eq!(SKU::from_bytes(&sku.to_bytes()).unwrap(), sku);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut flags: u16 = 0;
+
+        if self.craftable {
+            flags |= FLAG_CRAFTABLE;
+        }
+        if self.australium {
+            flags |= FLAG_AUSTRALIUM;
+        }
+        if self.strange {
+            flags |= FLAG_STRANGE;
+        }
+        if self.festivized {
+            flags |= FLAG_FESTIVIZED;
+        }
+        if self.particle.is_some() {
+            flags |= FLAG_PARTICLE;
+        }
+        if self.skin.is_some() {
+            flags |= FLAG_SKIN;
+        }
+        if self.killstreak_tier.is_some() {
+            flags |= FLAG_KILLSTREAK_TIER;
+        }
+        if self.wear.is_some() {
+            flags |= FLAG_WEAR;
+        }
+        if self.target_defindex.is_some() {
+            flags |= FLAG_TARGET_DEFINDEX;
+        }
+        if self.output_defindex.is_some() {
+            flags |= FLAG_OUTPUT_DEFINDEX;
+        }
+        if self.output_quality.is_some() {
+            flags |= FLAG_OUTPUT_QUALITY;
+        }
+        if self.craft_number.is_some() {
+            flags |= FLAG_CRAFT_NUMBER;
+        }
+        if self.crate_number.is_some() {
+            flags |= FLAG_CRATE_NUMBER;
+        }
+        if self.paint.is_some() {
+            flags |= FLAG_PAINT;
+        }
+        if self.sheen.is_some() {
+            flags |= FLAG_SHEEN;
+        }
+        if self.killstreaker.is_some() {
+            flags |= FLAG_KILLSTREAKER;
+        }
+
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(&flags.to_le_bytes());
+        write_varint(&mut bytes, zigzag_encode(self.defindex));
+        write_varint(&mut bytes, u32::from(self.quality).into());
+
+        if let Some(particle) = self.particle {
+            write_varint(&mut bytes, particle.into());
+        }
+        if let Some(skin) = self.skin {
+            write_varint(&mut bytes, skin.into());
+        }
+        if let Some(killstreak_tier) = self.killstreak_tier {
+            write_varint(&mut bytes, u32::from(killstreak_tier).into());
+        }
+        if let Some(wear) = self.wear {
+            write_varint(&mut bytes, u32::from(wear).into());
+        }
+        if let Some(target_defindex) = self.target_defindex {
+            write_varint(&mut bytes, target_defindex.into());
+        }
+        if let Some(output_defindex) = self.output_defindex {
+            write_varint(&mut bytes, output_defindex.into());
+        }
+        if let Some(output_quality) = self.output_quality {
+            write_varint(&mut bytes, u32::from(output_quality).into());
+        }
+        if let Some(craft_number) = self.craft_number {
+            write_varint(&mut bytes, craft_number.into());
+        }
+        if let Some(crate_number) = self.crate_number {
+            write_varint(&mut bytes, crate_number.into());
+        }
+        if let Some(paint) = self.paint {
+            write_varint(&mut bytes, u32::from(paint).into());
+        }
+        if let Some(sheen) = self.sheen {
+            write_varint(&mut bytes, u32::from(sheen).into());
+        }
+        if let Some(killstreaker) = self.killstreaker {
+            write_varint(&mut bytes, u32::from(killstreaker).into());
+        }
+
+        bytes
+    }
+
+    /// Decodes a SKU from the compact binary form produced by [`SKU::to_bytes`]. Fails if `bytes`
+    /// is truncated, has trailing data left over after a complete SKU, or an enum field's
+    /// primitive value does not correspond to any known variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tf2_sku::{SKU, tf2_enum::Quality};
+    ///
+    /// // negative defindex round-trips losslessly
+    /// let sku = SKU::new(-1, Quality::Strange);
+    ///
+    /// assert_eq!(SKU::from_bytes(&sku.to_bytes()).unwrap(), sku);
+    /// assert!(SKU::from_bytes(&[]).is_err());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let flags_bytes = bytes.get(0..2)
+            .ok_or(DecodeError::UnexpectedEof)?;
+        let flags = u16::from_le_bytes([flags_bytes[0], flags_bytes[1]]);
+        let mut pos = 2;
+
+        let defindex = zigzag_decode(read_varint(bytes, &mut pos)?);
+        let quality = decode_enum_u32("quality", read_varint_u32(bytes, &mut pos, "quality")?)?;
+        let mut parsed = SKU::new(defindex, quality);
+
+        parsed.craftable = flags & FLAG_CRAFTABLE != 0;
+        parsed.australium = flags & FLAG_AUSTRALIUM != 0;
+        parsed.strange = flags & FLAG_STRANGE != 0;
+        parsed.festivized = flags & FLAG_FESTIVIZED != 0;
+
+        if flags & FLAG_PARTICLE != 0 {
+            parsed.particle = Some(read_varint_u32(bytes, &mut pos, "particle")?);
+        }
+        if flags & FLAG_SKIN != 0 {
+            parsed.skin = Some(read_varint_u32(bytes, &mut pos, "skin")?);
+        }
+        if flags & FLAG_KILLSTREAK_TIER != 0 {
+            parsed.killstreak_tier = Some(decode_enum_u32("killstreak tier", read_varint_u32(bytes, &mut pos, "killstreak tier")?)?);
+        }
+        if flags & FLAG_WEAR != 0 {
+            parsed.wear = Some(decode_enum_u32("wear", read_varint_u32(bytes, &mut pos, "wear")?)?);
+        }
+        if flags & FLAG_TARGET_DEFINDEX != 0 {
+            parsed.target_defindex = Some(read_varint_u32(bytes, &mut pos, "target defindex")?);
+        }
+        if flags & FLAG_OUTPUT_DEFINDEX != 0 {
+            parsed.output_defindex = Some(read_varint_u32(bytes, &mut pos, "output defindex")?);
+        }
+        if flags & FLAG_OUTPUT_QUALITY != 0 {
+            parsed.output_quality = Some(decode_enum_u32("output quality", read_varint_u32(bytes, &mut pos, "output quality")?)?);
+        }
+        if flags & FLAG_CRAFT_NUMBER != 0 {
+            parsed.craft_number = Some(read_varint_u32(bytes, &mut pos, "craft number")?);
+        }
+        if flags & FLAG_CRATE_NUMBER != 0 {
+            parsed.crate_number = Some(read_varint_u32(bytes, &mut pos, "crate number")?);
+        }
+        if flags & FLAG_PAINT != 0 {
+            parsed.paint = Some(decode_enum_u32("paint", read_varint_u32(bytes, &mut pos, "paint")?)?);
+        }
+        if flags & FLAG_SHEEN != 0 {
+            parsed.sheen = Some(decode_enum_u32("sheen", read_varint_u32(bytes, &mut pos, "sheen")?)?);
+        }
+        if flags & FLAG_KILLSTREAKER != 0 {
+            parsed.killstreaker = Some(decode_enum_u32("killstreaker", read_varint_u32(bytes, &mut pos, "killstreaker")?)?);
+        }
+
+        if pos != bytes.len() {
+            return Err(DecodeError::TrailingData);
+        }
+
+        Ok(parsed)
+    }
+
+    /// Encodes this SKU as [`SKU::to_bytes`], then as a URL-safe base64 string so it survives
+    /// JSON/URLs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tf2_sku::{SKU, tf2_enum::Quality};
+    ///
+    /// let sku = SKU::new(264, Quality::Strange);
+    ///
+    /// assert_eq!(SKU::from_base64(&sku.to_base64()).unwrap(), sku);
+    /// ```
+    pub fn to_base64(&self) -> String {
+        base64_encode(&self.to_bytes())
+    }
+
+    /// Decodes a SKU from the base64 form produced by [`SKU::to_base64`].
+    pub fn from_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_bytes(&base64_decode(s)?)
+    }
+}
+
+/// An error when decoding a SKU from its compact binary or base64 form.
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The input ended before a complete SKU could be decoded.
+    UnexpectedEof,
+    /// The input is not valid base64.
+    InvalidEncoding,
+    /// A varint ran on for more continuation bytes than any value it could represent requires.
+    VarintTooLong,
+    /// The input decoded successfully but had bytes left over after the last field.
+    TrailingData,
+    /// A decoded value is outside the range the field can hold.
+    ValueOutOfRange {
+        key: &'static str,
+        value: u64,
+    },
+    /// An enum field's primitive value does not correspond to any known variant.
+    InvalidValue {
+        key: &'static str,
+        number: u32,
+    },
+}
+
+impl std::error::Error for DecodeError {}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnexpectedEof => write!(f, "Unexpected end of input while decoding SKU"),
+            DecodeError::InvalidEncoding => write!(f, "Input is not valid base64"),
+            DecodeError::VarintTooLong => write!(f, "Varint in SKU bytes is too long"),
+            DecodeError::TrailingData => write!(f, "Input has trailing data after a complete SKU"),
+            DecodeError::ValueOutOfRange {
+                key,
+                value,
+            } => write!(f, "Value for {key} in SKU is out of range: {value}"),
+            DecodeError::InvalidValue {
+                key,
+                number,
+            } => write!(f, "Unknown {key}: {number}"),
+        }
+    }
+}
+
+fn decode_enum_u32<T>(key: &'static str, number: u32) -> Result<T, DecodeError>
+where T:
+    TryFromPrimitive<Primitive = u32>,
+{
+    T::try_from_primitive(number)
+        .map_err(|TryFromPrimitiveError { number }| DecodeError::InvalidValue {
+            key,
+            number,
         })
 }
 
+fn zigzag_encode(value: i32) -> u64 {
+    (((value << 1) ^ (value >> 31)) as u32).into()
+}
+
+fn zigzag_decode(value: u64) -> i32 {
+    let value = value as u32;
+
+    ((value >> 1) as i32) ^ -((value & 1) as i32)
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+
+        value >>= 7;
+
+        if value == 0 {
+            bytes.push(byte);
+            break;
+        }
+
+        bytes.push(byte | 0x80);
+    }
+}
+
+/// A u64 varint needs at most 10 bytes, 9 of them with the continuation bit set (`10 * 7 = 70`
+/// bits covers the 64 bits of payload). Any longer sequence is corrupt input, not a legitimately
+/// large value.
+const MAX_VARINT_BYTES: usize = 10;
+
+fn read_varint(bytes: &[u8], pos: &mut usize) -> Result<u64, DecodeError> {
+    let mut result: u64 = 0;
+    let mut shift = 0;
+
+    for _ in 0..MAX_VARINT_BYTES {
+        let byte = *bytes.get(*pos).ok_or(DecodeError::UnexpectedEof)?;
+
+        *pos += 1;
+        result |= ((byte & 0x7f) as u64) << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+
+        shift += 7;
+    }
+
+    Err(DecodeError::VarintTooLong)
+}
+
+fn read_varint_u32(bytes: &[u8], pos: &mut usize, key: &'static str) -> Result<u32, DecodeError> {
+    let value = read_varint(bytes, pos)?;
+
+    u32::try_from(value).map_err(|_| DecodeError::ValueOutOfRange { key, value })
+}
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied().unwrap_or(0);
+        let b2 = chunk.get(2).copied().unwrap_or(0);
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+
+        if chunk.len() > 1 {
+            out.push(BASE64_ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char);
+        }
+        if chunk.len() > 2 {
+            out.push(BASE64_ALPHABET[(b2 & 0x3f) as usize] as char);
+        }
+    }
+
+    out
+}
+
+fn base64_decode_char(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'-' => Some(62),
+        b'_' => Some(63),
+        _ => None,
+    }
+}
+
+fn base64_decode(s: &str) -> Result<Vec<u8>, DecodeError> {
+    let chars = s.bytes()
+        .map(|c| base64_decode_char(c).ok_or(DecodeError::InvalidEncoding))
+        .collect::<Result<Vec<u8>, _>>()?;
+
+    // a single leftover character carries only 6 bits, which cannot make up a byte
+    if chars.len() % 4 == 1 {
+        return Err(DecodeError::InvalidEncoding);
+    }
+
+    let mut out = Vec::with_capacity(chars.len() * 3 / 4);
+
+    for chunk in chars.chunks(4) {
+        let c0 = chunk[0];
+        let c1 = chunk.get(1).copied().unwrap_or(0);
+        let c2 = chunk.get(2).copied().unwrap_or(0);
+        let c3 = chunk.get(3).copied().unwrap_or(0);
+
+        out.push((c0 << 2) | (c1 >> 4));
+        if chunk.len() > 2 {
+            out.push((c1 << 4) | (c2 >> 2));
+        }
+        if chunk.len() > 3 {
+            out.push((c2 << 6) | c3);
+        }
+    }
+
+    Ok(out)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -550,18 +1376,77 @@ mod tests {
     
     #[test]
     fn bad_quality_is_err_check_error_key() {
-        if let ParseError::InvalidValue { key, number } = SKU::try_from("1071;122").unwrap_err() {
+        if let ParseError::InvalidValue { key, number, .. } = SKU::try_from("1071;122").unwrap_err() {
             assert_eq!(key, "quality");
             assert_eq!(number, 122);
         } else {
             panic!("wrong error");
         }
     }
-    
+
+    #[test]
+    fn error_reports_segment_and_span() {
+        let error = SKU::try_from("424;15;u703;w3;pk307;kt-3;ks-1;ke-").unwrap_err();
+
+        assert_eq!(error.segment(), 7);
+        assert_eq!(error.span(), 31..34);
+        assert_eq!(&"424;15;u703;w3;pk307;kt-3;ks-1;ke-"[error.span()], "ke-");
+    }
+
     #[test]
     fn negative_defindex_is_ok() {
         assert!(SKU::try_from("-1;11").is_ok());
     }
+
+    #[test]
+    fn duplicate_attribute_is_ok_by_default() {
+        let sku = SKU::try_from_with_options("264;11;kt-1;kt-3", &SkuParseOptions::default())
+            .unwrap();
+
+        assert_eq!(sku.killstreak_tier, Some(KillstreakTier::Professional));
+    }
+
+    #[test]
+    fn reject_duplicate_attributes_is_err() {
+        let options = SkuParseOptions {
+            reject_duplicate_attributes: true,
+            ..SkuParseOptions::default()
+        };
+
+        assert!(matches!(
+            SKU::try_from_with_options("264;11;kt-1;kt-3", &options),
+            Err(ParseError::DuplicateAttribute { key: "killstreak tier", .. }),
+        ));
+    }
+
+    #[test]
+    fn allow_missing_quality_with_no_quality_segment_uses_default() {
+        let options = SkuParseOptions {
+            allow_missing_quality: true,
+            default_quality: Quality::Unique,
+            ..SkuParseOptions::default()
+        };
+        let sku = SKU::try_from_with_options("264", &options).unwrap();
+
+        assert_eq!(sku.quality, Quality::Unique);
+    }
+
+    #[test]
+    fn allow_missing_quality_with_unrecognized_quality_uses_default() {
+        let options = SkuParseOptions {
+            allow_missing_quality: true,
+            default_quality: Quality::Unique,
+            ..SkuParseOptions::default()
+        };
+        let sku = SKU::try_from_with_options("264;9999", &options).unwrap();
+
+        assert_eq!(sku.quality, Quality::Unique);
+    }
+
+    #[test]
+    fn missing_quality_is_err_by_default() {
+        assert!(SKU::try_from_with_options("264", &SkuParseOptions::default()).is_err());
+    }
     
     #[test]
     fn paint_kit_correct() {
@@ -585,10 +1470,141 @@ mod tests {
         assert_eq!(s, r#"{"sku":"16310;15;u703;w2;pk310"}"#);
     }
     
+    #[test]
+    fn deserializes_from_json_object() {
+        let item = serde_json::from_value::<Item>(json!({
+            "sku": {
+                "defindex": 424,
+                "quality": "Decorated Weapon",
+                "particle": 703,
+                "wear": "Field-Tested",
+                "skin": 307,
+                "killstreak_tier": "Professional Killstreak",
+                "sheen": "Team Shine",
+                "killstreaker": "Hypno-Beam",
+            }
+        })).unwrap();
+
+        assert_eq!(item.sku, SKU::try_from("424;15;u703;w3;pk307;kt-3;ks-1;ke-2008").unwrap());
+    }
+
+    #[test]
+    fn deserializes_from_json_object_missing_quality_is_err() {
+        let result = serde_json::from_value::<Item>(json!({
+            "sku": {
+                "defindex": 424,
+            }
+        }));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn serializes_struct_to_json_skips_defaults() {
+        let sku = SKU::try_from("264;11;kt-3").unwrap();
+        let s = serde_json::to_string(&SkuStruct(sku)).unwrap();
+
+        assert_eq!(s, r#"{"defindex":264,"quality":"Strange","killstreak_tier":"Professional Killstreak"}"#);
+    }
+
+    #[test]
+    fn serializes_struct_to_json_with_flags() {
+        let sku = SKU::try_from("16310;15;uncraftable;australium;strange;festive;u703;w2;pk310").unwrap();
+        let s = serde_json::to_string(&SkuStruct(sku)).unwrap();
+
+        assert_eq!(
+            s,
+            r#"{"defindex":16310,"quality":"Decorated Weapon","craftable":false,"australium":true,"strange":true,"festivized":true,"particle":703,"skin":310,"wear":"Minimal Wear"}"#,
+        );
+    }
+
     #[test]
     fn to_sku_string_in_arc() {
         let sku = Arc::new(SKU::try_from("16310;15;u703;w2;pk310").unwrap());
-        
+
         assert_eq!(sku.as_ref().to_sku_string(), "16310;15;u703;w2;pk310");
     }
+
+    #[test]
+    fn bytes_round_trip() {
+        let sku = SKU::try_from("424;15;u703;w3;pk307;kt-3;ks-1;ke-2008").unwrap();
+
+        assert_eq!(SKU::from_bytes(&sku.to_bytes()).unwrap(), sku);
+    }
+
+    #[test]
+    fn bytes_round_trip_negative_defindex() {
+        let sku = SKU::new(-1, Quality::Strange);
+
+        assert_eq!(SKU::from_bytes(&sku.to_bytes()).unwrap(), sku);
+    }
+
+    #[test]
+    fn bytes_reject_truncated_input() {
+        let sku = SKU::try_from("424;15;u703;w3;pk307;kt-3;ks-1;ke-2008").unwrap();
+        let bytes = sku.to_bytes();
+
+        assert!(SKU::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(SKU::from_bytes(&[]).is_err());
+    }
+
+    #[test]
+    fn bytes_reject_trailing_data() {
+        let sku = SKU::try_from("424;15;u703;w3;pk307;kt-3;ks-1;ke-2008").unwrap();
+        let mut bytes = sku.to_bytes();
+
+        bytes.push(0xff);
+
+        assert!(matches!(SKU::from_bytes(&bytes), Err(DecodeError::TrailingData)));
+    }
+
+    #[test]
+    fn bytes_reject_out_of_range_enum() {
+        // `killstreak tier` bit set but the varint value has no matching `KillstreakTier` variant
+        let mut bytes = SKU::new(1, Quality::Strange).to_bytes();
+        let flags = u16::from_le_bytes([bytes[0], bytes[1]]) | (1 << 6);
+
+        bytes[0..2].copy_from_slice(&flags.to_le_bytes());
+        write_varint(&mut bytes, 255);
+
+        assert!(matches!(
+            SKU::from_bytes(&bytes),
+            Err(DecodeError::InvalidValue { key: "killstreak tier", .. }),
+        ));
+    }
+
+    #[test]
+    fn bytes_reject_overlong_varint() {
+        let mut bytes = vec![0u8, 0];
+
+        bytes.extend(std::iter::repeat(0x80).take(11));
+
+        assert!(matches!(SKU::from_bytes(&bytes), Err(DecodeError::VarintTooLong)));
+    }
+
+    #[test]
+    fn bytes_reject_out_of_range_u32() {
+        let mut bytes = SKU::new(1, Quality::Strange).to_bytes();
+        let flags = u16::from_le_bytes([bytes[0], bytes[1]]) | FLAG_PARTICLE;
+
+        bytes[0..2].copy_from_slice(&flags.to_le_bytes());
+        write_varint(&mut bytes, 1u64 << 32);
+
+        assert!(matches!(
+            SKU::from_bytes(&bytes),
+            Err(DecodeError::ValueOutOfRange { key: "particle", value }) if value == 1u64 << 32,
+        ));
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        let sku = SKU::try_from("424;15;u703;w3;pk307;kt-3;ks-1;ke-2008").unwrap();
+
+        assert_eq!(SKU::from_base64(&sku.to_base64()).unwrap(), sku);
+    }
+
+    #[test]
+    fn base64_rejects_invalid_characters() {
+        assert!(SKU::from_base64("not valid base64!!").is_err());
+    }
 }
\ No newline at end of file